@@ -1,29 +1,51 @@
 use pyo3::prelude::*;
 
+// HMAC
+pub const HS256: &str = "HS256";
+pub const HS384: &str = "HS384";
+pub const HS512: &str = "HS512";
+
+// RSA
+pub const RS256: &str = "RS256";
+pub const RS384: &str = "RS384";
+pub const RS512: &str = "RS512";
+
+// ECDSA
+pub const ES256: &str = "ES256";
+pub const ES384: &str = "ES384";
+
+// RSA-PSS
+pub const PS256: &str = "PS256";
+pub const PS384: &str = "PS384";
+pub const PS512: &str = "PS512";
+
+// Edwards Curve
+pub const EDDSA: &str = "EdDSA";
+
 pub fn register_algorithms_module(py: Python) -> PyResult<Py<PyModule>>{
     let alg_module = PyModule::new(py, "algorithms")?;
 
     // HMAC
-    alg_module.add("HS256", "HS256")?;
-    alg_module.add("HS384", "HS384")?;
-    alg_module.add("HS512", "HS512")?;
+    alg_module.add("HS256", HS256)?;
+    alg_module.add("HS384", HS384)?;
+    alg_module.add("HS512", HS512)?;
 
     // RSA
-    alg_module.add("RS256", "RS256")?;
-    alg_module.add("RS384", "RS384")?;
-    alg_module.add("RS512", "RS512")?;
+    alg_module.add("RS256", RS256)?;
+    alg_module.add("RS384", RS384)?;
+    alg_module.add("RS512", RS512)?;
 
     // ECDSA
-    alg_module.add("ES256", "ES256")?;
-    alg_module.add("ES384", "ES384")?;
+    alg_module.add("ES256", ES256)?;
+    alg_module.add("ES384", ES384)?;
 
     // RSA-PSS
-    alg_module.add("PS256", "PS256")?;
-    alg_module.add("PS384", "PS384")?;
-    alg_module.add("PS512", "PS512")?;
+    alg_module.add("PS256", PS256)?;
+    alg_module.add("PS384", PS384)?;
+    alg_module.add("PS512", PS512)?;
 
     // Edwards Curve
-    alg_module.add("EDDSA", "EdDSA")?;
+    alg_module.add("EDDSA", EDDSA)?;
 
     Ok(alg_module.into())
 }
@@ -0,0 +1,85 @@
+use pyo3::prelude::*;
+use base64::{engine::general_purpose, Engine as _};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Key, Nonce,
+};
+
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+fn validate_key(key: &[u8]) -> PyResult<()> {
+    if key.len() != KEY_LEN {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "key must be exactly {} bytes, got {}",
+            KEY_LEN,
+            key.len()
+        )));
+    }
+    Ok(())
+}
+
+#[pyfunction]
+/// encrypt a claims payload with AES-256-GCM, returning a compact
+/// `base64url(nonce).base64url(ciphertext||tag)` token. `key` must be a
+/// 32-byte secret, e.g. the base64-decoded output of
+/// `secret.generate_hmac_secret(32)` or `keyexchange.derive_shared_secret`
+pub fn encrypt_payload(plaintext: &[u8], key: &[u8]) -> PyResult<String> {
+    validate_key(key)?;
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Encryption failed: {}", e)))?;
+
+    let nonce_b64 = general_purpose::URL_SAFE_NO_PAD.encode(nonce_bytes);
+    let ciphertext_b64 = general_purpose::URL_SAFE_NO_PAD.encode(ciphertext);
+
+    Ok(format!("{}.{}", nonce_b64, ciphertext_b64))
+}
+
+#[pyfunction]
+/// decrypt a token produced by `encrypt_payload`, returning the original
+/// plaintext bytes. Raises `PyValueError` on any authentication failure
+pub fn decrypt_payload(token: &str, key: &[u8]) -> PyResult<Vec<u8>> {
+    validate_key(key)?;
+
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() != 2 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Invalid encrypted token format: expected nonce.ciphertext",
+        ));
+    }
+
+    let nonce_bytes = general_purpose::URL_SAFE_NO_PAD
+        .decode(parts[0])
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid base64 nonce: {}", e)))?;
+    let ciphertext = general_purpose::URL_SAFE_NO_PAD
+        .decode(parts[1])
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid base64 ciphertext: {}", e)))?;
+
+    if nonce_bytes.len() != NONCE_LEN {
+        return Err(pyo3::exceptions::PyValueError::new_err("Invalid nonce length"));
+    }
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    cipher
+        .decrypt(nonce, ciphertext.as_ref())
+        .map_err(|_| pyo3::exceptions::PyValueError::new_err("Failed to decrypt: authentication failed"))
+}
+
+pub fn register_encrypt_module(py: Python) -> PyResult<Py<PyModule>> {
+    let m = PyModule::new(py, "encrypt")?;
+    m.add_function(wrap_pyfunction!(encrypt_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(decrypt_payload, m)?)?;
+    Ok(m.into())
+}
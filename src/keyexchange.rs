@@ -0,0 +1,51 @@
+use pyo3::prelude::*;
+use base64::{engine::general_purpose, Engine as _};
+use rand::rngs::OsRng;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+#[pyfunction]
+/// generate an X25519 keypair for ECDH shared-secret derivation, returned
+/// as base64-encoded 32-byte values
+pub fn generate_x25519_keypair() -> PyResult<(String, String)> {
+    let private_key = StaticSecret::random_from_rng(OsRng);
+    let public_key = PublicKey::from(&private_key);
+
+    let private_b64 = general_purpose::STANDARD.encode(private_key.to_bytes());
+    let public_b64 = general_purpose::STANDARD.encode(public_key.to_bytes());
+
+    Ok((private_b64, public_b64))
+}
+
+#[pyfunction]
+/// derive an X25519 Diffie-Hellman shared secret from a 32-byte private key
+/// and a peer's 32-byte public key
+pub fn derive_shared_secret(private_key_bytes: &[u8], peer_public_key_bytes: &[u8]) -> PyResult<Vec<u8>> {
+    if private_key_bytes.len() != 32 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "private_key_bytes must be exactly 32 bytes",
+        ));
+    }
+    if peer_public_key_bytes.len() != 32 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "peer_public_key_bytes must be exactly 32 bytes",
+        ));
+    }
+
+    let mut private_arr = [0u8; 32];
+    private_arr.copy_from_slice(private_key_bytes);
+    let mut public_arr = [0u8; 32];
+    public_arr.copy_from_slice(peer_public_key_bytes);
+
+    let private_key = StaticSecret::from(private_arr);
+    let public_key = PublicKey::from(public_arr);
+
+    let shared_secret = private_key.diffie_hellman(&public_key);
+    Ok(shared_secret.to_bytes().to_vec())
+}
+
+pub fn register_keyexchange_module(py: Python) -> PyResult<Py<PyModule>> {
+    let m = PyModule::new(py, "keyexchange")?;
+    m.add_function(wrap_pyfunction!(generate_x25519_keypair, m)?)?;
+    m.add_function(wrap_pyfunction!(derive_shared_secret, m)?)?;
+    Ok(m.into())
+}
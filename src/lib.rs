@@ -5,15 +5,19 @@ use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header,
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::HashMap;
 use uuid::Uuid;
 use tokio::task;
 use tokio::runtime::Builder;
+use base64::{engine::general_purpose, Engine as _};
 
 
 mod secret;
 mod time;
 mod algorithms;
 mod utils;
+mod keyexchange;
+mod encrypt;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
@@ -27,12 +31,177 @@ pub struct Claims {
     pub extra: Map<String, Value>,
 }
 
+// a single key in a JWKS-style keyset, selected by `kid` during decode
+#[derive(Debug, Clone)]
+struct KeyEntry {
+    secret: String,
+    algorithm: Algorithm,
+}
+
 #[pyclass]
 pub struct CipherToken {
     secret: String,
     algorithm: Algorithm,
     access_ttl: u64,
     refresh_ttl: u64,
+    keyset: Option<HashMap<String, KeyEntry>>,
+    // kid stamped onto tokens minted by create_token/access/refresh (and
+    // their async variants) when this instance was built via from_jwks, so a
+    // kid-selecting verifier can find them again. Tokens minted by
+    // rotation_async carry no kid header
+    default_kid: Option<String>,
+}
+
+// jsonwebtoken::Validation::new defaults to 60s of clock-skew leeway; mirror
+// that here so constructing ValidationOptions for an unrelated check (e.g.
+// just an issuer) doesn't silently tighten exp/nbf leeway to 0
+const DEFAULT_LEEWAY_SECS: u64 = 60;
+
+/// standards-compliant claim validation options for `decode`/`decode_async`:
+/// audience, issuer, subject, nbf and clock-skew leeway. Unset fields keep
+/// today's exp-only default behavior
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ValidationOptions {
+    #[pyo3(get, set)]
+    pub audience: Option<Vec<String>>,
+    #[pyo3(get, set)]
+    pub issuer: Option<Vec<String>>,
+    #[pyo3(get, set)]
+    pub subject: Option<String>,
+    #[pyo3(get, set)]
+    pub required_claims: Option<Vec<String>>,
+    #[pyo3(get, set)]
+    pub validate_nbf: bool,
+    #[pyo3(get, set)]
+    pub leeway: u64,
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        ValidationOptions {
+            audience: None,
+            issuer: None,
+            subject: None,
+            required_claims: None,
+            validate_nbf: false,
+            leeway: DEFAULT_LEEWAY_SECS,
+        }
+    }
+}
+
+#[pymethods]
+impl ValidationOptions {
+    #[new]
+    #[pyo3(signature = (audience=None, issuer=None, subject=None, required_claims=None, validate_nbf=false, leeway=DEFAULT_LEEWAY_SECS))]
+    pub fn new(
+        audience: Option<Vec<String>>,
+        issuer: Option<Vec<String>>,
+        subject: Option<String>,
+        required_claims: Option<Vec<String>>,
+        validate_nbf: bool,
+        leeway: u64,
+    ) -> Self {
+        ValidationOptions {
+            audience,
+            issuer,
+            subject,
+            required_claims,
+            validate_nbf,
+            leeway,
+        }
+    }
+}
+
+// apply optional rich validation settings onto a jsonwebtoken Validation,
+// leaving today's exp-only defaults untouched when no options are given
+fn apply_validation_options(validation: &mut Validation, options: Option<&ValidationOptions>) {
+    if let Some(options) = options {
+        if let Some(ref audience) = options.audience {
+            validation.set_audience(audience);
+        }
+        if let Some(ref issuer) = options.issuer {
+            validation.set_issuer(issuer);
+        }
+        if let Some(ref subject) = options.subject {
+            validation.sub = Some(subject.clone());
+        }
+        if let Some(ref required_claims) = options.required_claims {
+            validation.set_required_spec_claims(required_claims);
+        }
+        validation.validate_nbf = options.validate_nbf;
+        validation.leeway = options.leeway;
+    }
+}
+
+// build a jsonwebtoken Header from an optional Python dict of header overrides
+// (kid, cty, typ, x5t), defaulting alg to the configured algorithm. When the
+// caller doesn't set `kid` explicitly, `default_kid` (the signing key chosen
+// by `from_jwks`) is stamped on instead, so kid-selecting verifiers can match
+// tokens minted from a keyset
+fn build_header(algorithm: Algorithm, header: Option<&PyDict>, default_kid: Option<&str>) -> PyResult<Header> {
+    let mut jwt_header = Header::new(algorithm);
+    jwt_header.kid = default_kid.map(|kid| kid.to_string());
+
+    if let Some(header_dict) = header {
+        if let Some(value) = header_dict.get_item("kid")? {
+            jwt_header.kid = Some(value.extract::<String>()?);
+        }
+        if let Some(value) = header_dict.get_item("cty")? {
+            jwt_header.cty = Some(value.extract::<String>()?);
+        }
+        if let Some(value) = header_dict.get_item("typ")? {
+            jwt_header.typ = Some(value.extract::<String>()?);
+        }
+        if let Some(value) = header_dict.get_item("x5t")? {
+            jwt_header.x5t = Some(value.extract::<String>()?);
+        }
+    }
+
+    Ok(jwt_header)
+}
+
+// render a jsonwebtoken Header as a nested dict (alg, typ, kid, cty, x5t),
+// so verified decode results can surface which key/algorithm signed the token
+fn header_to_dict(py: Python, header: &Header) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("alg", format!("{:?}", header.alg))?;
+    if let Some(ref typ) = header.typ {
+        dict.set_item("typ", typ)?;
+    }
+    if let Some(ref kid) = header.kid {
+        dict.set_item("kid", kid)?;
+    }
+    if let Some(ref cty) = header.cty {
+        dict.set_item("cty", cty)?;
+    }
+    if let Some(ref x5t) = header.x5t {
+        dict.set_item("x5t", x5t)?;
+    }
+    Ok(dict.into())
+}
+
+// add human-readable `*_datetime` / `is_expired` / `seconds_until_expiry`
+// fields alongside the raw numeric exp/nbf/iat claims, routed through the
+// `time` submodule so there is one canonical epoch->string formatter
+fn add_timestamp_fields(dict: &PyDict, exp: usize, extra: &Map<String, Value>) -> PyResult<()> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
+        .as_secs() as i64;
+    let exp_secs = exp as i64;
+
+    dict.set_item("exp_datetime", time::to_iso8601(exp_secs)?)?;
+    dict.set_item("is_expired", exp_secs <= now)?;
+    dict.set_item("seconds_until_expiry", exp_secs - now)?;
+
+    for key in ["iat", "nbf"] {
+        if let Some(secs) = extra.get(key).and_then(Value::as_i64) {
+            dict.set_item(format!("{}_datetime", key), time::to_iso8601(secs)?)?;
+        }
+    }
+
+    Ok(())
 }
 
 // supported algorithm
@@ -75,10 +244,89 @@ impl CipherToken {
             algorithm: alg,
             access_ttl,
             refresh_ttl,
+            keyset: None,
+            default_kid: None,
+        })
+    }
+
+    /// build a CipherToken from a JWKS-style keyset: a JSON string or dict
+    /// mapping `kid` -> {"alg": ..., "secret": ...}. Decode will pick the key
+    /// matching the token's `kid` header, falling back to trying every key
+    /// when the token has no `kid`. `default_kid` selects which key signs new
+    /// `access`/`refresh`/`create_token` tokens; when omitted, the
+    /// lexicographically smallest `kid` is used (a deterministic choice, but
+    /// not necessarily the first entry as written in the input). Tokens
+    /// minted this way carry a matching `kid` header so a kid-selecting
+    /// verifier can find them again
+    #[staticmethod]
+    #[pyo3(signature = (jwks, access_ttl, refresh_ttl, default_kid=None))]
+    pub fn from_jwks(
+        py: Python,
+        jwks: &PyAny,
+        access_ttl: u64,
+        refresh_ttl: u64,
+        default_kid: Option<String>,
+    ) -> PyResult<Self> {
+        let jwks_value = if let Ok(json_str) = jwks.extract::<String>() {
+            serde_json::from_str::<Value>(&json_str)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid JWKS JSON: {}", e)))?
+        } else if let Ok(dict) = jwks.downcast::<PyDict>() {
+            python_to_json(py, dict)?
+        } else {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "jwks must be a JSON string or a dict mapping kid to {alg, secret}",
+            ));
+        };
+
+        let jwks_map = jwks_value.as_object().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("jwks must be an object mapping kid to key info")
+        })?;
+
+        let mut keyset = HashMap::new();
+        for (kid, entry) in jwks_map {
+            let entry_obj = entry.as_object().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!("Key entry for kid '{}' must be an object", kid))
+            })?;
+            let alg_str = entry_obj.get("alg").and_then(Value::as_str).ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!("Key entry for kid '{}' is missing 'alg'", kid))
+            })?;
+            let secret = entry_obj.get("secret").and_then(Value::as_str).ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!("Key entry for kid '{}' is missing 'secret'", kid))
+            })?;
+            let algorithm = parse_algorithm(alg_str)?;
+            keyset.insert(kid.clone(), KeyEntry { secret: secret.to_string(), algorithm });
+        }
+
+        if keyset.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err("jwks must contain at least one key"));
+        }
+
+        let chosen_kid = match default_kid {
+            Some(kid) => {
+                if !keyset.contains_key(&kid) {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "default_kid '{}' not found in jwks",
+                        kid
+                    )));
+                }
+                kid
+            }
+            None => keyset.keys().min().cloned().unwrap(),
+        };
+        let chosen = keyset.get(&chosen_kid).unwrap();
+        let (default_secret, default_algorithm) = (chosen.secret.clone(), chosen.algorithm);
+
+        Ok(CipherToken {
+            secret: default_secret,
+            algorithm: default_algorithm,
+            access_ttl,
+            refresh_ttl,
+            default_kid: Some(chosen_kid),
+            keyset: Some(keyset),
         })
     }
 
-    #[pyo3(signature = (ttl_time, token_type, user_id=None, extra_payload=None))]
+    #[pyo3(signature = (ttl_time, token_type, user_id=None, extra_payload=None, header=None))]
     pub fn create_token(
         &self,
         py: Python,
@@ -86,6 +334,7 @@ impl CipherToken {
         token_type: String,
         user_id: Option<i128>,
         extra_payload: Option<&PyDict>,
+        header: Option<&PyDict>,
     ) -> PyResult<String> {
         let uuid = Uuid::new_v4();
 
@@ -134,8 +383,10 @@ impl CipherToken {
                 .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
         };
 
+        let jwt_header = build_header(self.algorithm, header, self.default_kid.as_deref())?;
+
         let token = encode(
-            &Header::new(self.algorithm),
+            &jwt_header,
             &claims,
             &encoding_key,
         )
@@ -145,12 +396,13 @@ impl CipherToken {
     }
 
     /// create access token - sync
-    #[pyo3(signature = (user_id, extra_payload=None))]
+    #[pyo3(signature = (user_id, extra_payload=None, header=None))]
     pub fn access(
         &self,
         py: Python,
         user_id: i128,
         extra_payload: Option<&PyDict>,
+        header: Option<&PyDict>,
     ) -> PyResult<String> {
         self.create_token(
             py,
@@ -158,16 +410,18 @@ impl CipherToken {
             "access".to_string(),
             Some(user_id),
             extra_payload,
+            header,
         )
     }
 
     /// create refresh token - sync
-    #[pyo3(signature = (user_id, extra_payload=None))]
+    #[pyo3(signature = (user_id, extra_payload=None, header=None))]
     pub fn refresh(
         &self,
         py: Python,
         user_id: i128,
         extra_payload: Option<&PyDict>,
+        header: Option<&PyDict>,
     ) -> PyResult<String> {
         self.create_token(
             py,
@@ -175,36 +429,26 @@ impl CipherToken {
             "refresh".to_string(),
             Some(user_id),
             extra_payload,
+            header,
         )
     }
 
-    /// decode token - sync
-    pub fn decode<'a>(&self, py: Python<'a>, token: &str) -> PyResult<Py<PyDict>> {
+    /// decode token - sync. When this CipherToken was built via `from_jwks`,
+    /// the keyset is consulted the same way `decode_async` does: the token's
+    /// `kid` header selects the matching key, falling back to trying every
+    /// key when the token has no `kid`. Returns the same shape as
+    /// `decode_async`, including the verified `header` dict, so callers
+    /// can check which key/algorithm signed the token after verification
+    #[pyo3(signature = (token, options=None))]
+    pub fn decode<'a>(&self, py: Python<'a>, token: &str, options: Option<ValidationOptions>) -> PyResult<Py<PyDict>> {
         let mut validation = Validation::new(self.algorithm);
         validation.validate_exp = true;
         validation.required_spec_claims.clear();
+        apply_validation_options(&mut validation, options.as_ref());
 
-        let decoding_key = match self.algorithm {
-            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => {
-                DecodingKey::from_secret(self.secret.as_bytes())
-            }
-            Algorithm::RS256
-            | Algorithm::RS384
-            | Algorithm::RS512
-            | Algorithm::PS256
-            | Algorithm::PS384
-            | Algorithm::PS512 => DecodingKey::from_rsa_pem(self.secret.as_bytes())
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
-            Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(self.secret.as_bytes())
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
-            Algorithm::EdDSA => DecodingKey::from_ed_pem(self.secret.as_bytes())
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
-        };
-
-        let token_data: TokenData<Claims> =
-            decode::<Claims>(token, &decoding_key, &validation)
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        let token_data: TokenData<Claims> = self.decode_claims(token, &validation)?;
 
+        let header = header_to_dict(py, &token_data.header)?;
         let claims = token_data.claims;
         let dict = PyDict::new(py);
 
@@ -215,6 +459,7 @@ impl CipherToken {
         dict.set_item("ttl", claims.ttl)?;
         dict.set_item("token", claims.token)?;
         dict.set_item("jti", claims.jti)?;
+        dict.set_item("header", header)?;
 
         for (key, value) in claims.extra {
             let py_value = json_to_python(py, &value)?;
@@ -232,7 +477,7 @@ impl CipherToken {
         refresh_token: String,
         extra_payload: Option<&PyDict>,
     ) -> PyResult<(String, String)> {
-        let claims_dict = self.decode(py, &refresh_token)?;
+        let claims_dict = self.decode(py, &refresh_token, None)?;
         let claims_dict = claims_dict.as_ref(py);
         
         let token_type: String = claims_dict
@@ -251,35 +496,19 @@ impl CipherToken {
             .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("User ID not found"))?
             .extract()?;
 
-        let new_access = self.access(py, user_id, extra_payload.clone())?;
-        let new_refresh = self.refresh(py, user_id, extra_payload)?;
+        let new_access = self.access(py, user_id, extra_payload.clone(), None)?;
+        let new_refresh = self.refresh(py, user_id, extra_payload, None)?;
 
         Ok((new_access, new_refresh))
     }
 
-    /// verify token - sync
+    /// verify token - sync. Consults the keyset (kid-based, same as `decode`)
+    /// when this CipherToken was built via `from_jwks`
     pub fn verify(&self, token: &str) -> PyResult<bool> {
         let mut validation = Validation::new(self.algorithm);
         validation.validate_exp = true;
 
-        let decoding_key = match self.algorithm {
-            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => {
-                DecodingKey::from_secret(self.secret.as_bytes())
-            }
-            Algorithm::RS256
-            | Algorithm::RS384
-            | Algorithm::RS512
-            | Algorithm::PS256
-            | Algorithm::PS384
-            | Algorithm::PS512 => DecodingKey::from_rsa_pem(self.secret.as_bytes())
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
-            Algorithm::ES256 | Algorithm::ES384 => DecodingKey::from_ec_pem(self.secret.as_bytes())
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
-            Algorithm::EdDSA => DecodingKey::from_ed_pem(self.secret.as_bytes())
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
-        };
-
-        match decode::<Claims>(token, &decoding_key, &validation) {
+        match self.decode_claims(token, &validation) {
             Ok(_) => Ok(true),
             Err(_) => Ok(false),
         }
@@ -331,6 +560,14 @@ impl CipherToken {
         }
     }
 
+    /// unverified decode - splits and base64-decodes the header and payload
+    /// without checking the signature or expiry. DANGEROUS: never trust the
+    /// result of this for authorization decisions, use `decode`/`decode_async`
+    #[pyo3(signature = (token, include_timestamps=false))]
+    pub fn decode_insecure<'a>(&self, py: Python<'a>, token: &str, include_timestamps: bool) -> PyResult<Py<PyDict>> {
+        decode_insecure_inner(py, token, include_timestamps)
+    }
+
     /// remaining time - sync
     pub fn remaining_time(&self, py: Python, token: &str) -> PyResult<Option<i64>> {
         let dict = self.inspect(py, token)?;
@@ -395,17 +632,19 @@ impl CipherToken {
 
     // Async methods
     /// create access token - async
-    #[pyo3(signature = (user_id, extra_payload=None))]
+    #[pyo3(signature = (user_id, extra_payload=None, header=None))]
     pub fn access_async<'a>(
         &'a self,
         py: Python<'a>,
         user_id: i128,
         extra_payload: Option<&PyDict>,
+        header: Option<&PyDict>,
     ) -> PyResult<&'a PyAny> {
         let secret = self.secret.clone();
         let algorithm = self.algorithm;
         let access_ttl = self.access_ttl;
         let extra_payload_cloned = extra_payload.map(|dict| dict.into());
+        let jwt_header = build_header(algorithm, header, self.default_kid.as_deref())?;
 
         future_into_py(py, async move {
             let uuid = Uuid::new_v4();
@@ -425,9 +664,9 @@ impl CipherToken {
             };
 
             let encoding_key = create_encoding_key(&secret, algorithm)?;
-            
+
             let token = task::spawn_blocking(move || {
-                encode(&Header::new(algorithm), &claims, &encoding_key)
+                encode(&jwt_header, &claims, &encoding_key)
             })
             .await
             .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
@@ -438,17 +677,19 @@ impl CipherToken {
     }
 
     /// create refresh token - async
-    #[pyo3(signature = (user_id, extra_payload=None))]
+    #[pyo3(signature = (user_id, extra_payload=None, header=None))]
     pub fn refresh_async<'a>(
         &'a self,
         py: Python<'a>,
         user_id: i128,
         extra_payload: Option<&PyDict>,
+        header: Option<&PyDict>,
     ) -> PyResult<&'a PyAny> {
         let secret = self.secret.clone();
         let algorithm = self.algorithm;
         let refresh_ttl = self.refresh_ttl;
         let extra_payload_cloned = extra_payload.map(|dict| dict.into());
+        let jwt_header = build_header(algorithm, header, self.default_kid.as_deref())?;
 
         future_into_py(py, async move {
             let uuid = Uuid::new_v4();
@@ -468,9 +709,9 @@ impl CipherToken {
             };
 
             let encoding_key = create_encoding_key(&secret, algorithm)?;
-            
+
             let token = task::spawn_blocking(move || {
-                encode(&Header::new(algorithm), &claims, &encoding_key)
+                encode(&jwt_header, &claims, &encoding_key)
             })
             .await
             .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
@@ -481,46 +722,18 @@ impl CipherToken {
     }
 
     /// decode token - async
+    #[pyo3(signature = (token, options=None, include_timestamps=false))]
     pub fn decode_async<'a>(
         &'a self,
         py: Python<'a>,
         token: String,
+        options: Option<ValidationOptions>,
+        include_timestamps: bool,
     ) -> PyResult<&'a PyAny> {
-        let secret = self.secret.clone();
-        let algorithm = self.algorithm;
+        let token_instance = self.clone_token();
 
         future_into_py(py, async move {
-            let mut validation = Validation::new(algorithm);
-            validation.validate_exp = true;
-            validation.required_spec_claims.clear();
-
-            let decoding_key = create_decoding_key(&secret, algorithm)?;
-            
-            let token_data: TokenData<Claims> = task::spawn_blocking(move || {
-                decode::<Claims>(&token, &decoding_key, &validation)
-            })
-            .await
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
-            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
-
-            let py_dict = Python::with_gil(|py| {
-                let dict = PyDict::new(py);
-                if let Some(id) = token_data.claims.id {
-                    dict.set_item("id", id)?;
-                }
-                dict.set_item("exp", token_data.claims.exp)?;
-                dict.set_item("ttl", token_data.claims.ttl)?;
-                dict.set_item("token", token_data.claims.token)?;
-                dict.set_item("jti", token_data.claims.jti)?;
-
-                for (key, value) in token_data.claims.extra {
-                    let py_value = json_to_python(py, &value)?;
-                    dict.set_item(key, py_value)?;
-                }
-                Ok::<Py<PyDict>, PyErr>(dict.into())
-            })?;
-
-            Ok(py_dict)
+            token_instance.decode_async_inner(&token, options.as_ref(), include_timestamps).await
         })
     }
 
@@ -564,7 +777,7 @@ impl CipherToken {
         let extra_payload_cloned = extra_payload.map(|dict| dict.into());
 
         future_into_py(py, async move {
-            let claims_dict = token_instance.decode_async_inner(&refresh_token).await?;
+            let claims_dict = token_instance.decode_async_inner(&refresh_token, None, false).await?;
             
             let token_type: String = Python::with_gil(|py| {
                 claims_dict
@@ -596,14 +809,55 @@ impl CipherToken {
     }
 }
 
-// Helper functions for async operations
+// Helper functions shared by the sync and async methods
 impl CipherToken {
+    // pick the decoding key/algorithm for a token and run jsonwebtoken::decode,
+    // selecting a keyset entry by `kid` when this CipherToken was built via
+    // from_jwks (falling back to trying every key when the token has no
+    // `kid`), otherwise using the single configured secret/algorithm
+    fn decode_claims(&self, token: &str, base_validation: &Validation) -> PyResult<TokenData<Claims>> {
+        if let Some(keyset) = &self.keyset {
+            let kid = parse_header_kid(token)?;
+            let candidates: Vec<KeyEntry> = match kid {
+                Some(ref kid_value) => {
+                    let entry = keyset.get(kid_value).ok_or_else(|| {
+                        pyo3::exceptions::PyValueError::new_err(format!("No key found for kid: {}", kid_value))
+                    })?;
+                    vec![entry.clone()]
+                }
+                None => keyset.values().cloned().collect(),
+            };
+
+            let mut last_err = None;
+            for entry in candidates {
+                let mut entry_validation = base_validation.clone();
+                entry_validation.algorithms = vec![entry.algorithm];
+
+                let decoding_key = create_decoding_key(&entry.secret, entry.algorithm)?;
+                match decode::<Claims>(token, &decoding_key, &entry_validation) {
+                    Ok(data) => return Ok(data),
+                    Err(e) => last_err = Some(e),
+                }
+            }
+
+            Err(pyo3::exceptions::PyValueError::new_err(
+                last_err.map(|e| e.to_string()).unwrap_or_else(|| "No matching key found for token".to_string()),
+            ))
+        } else {
+            let decoding_key = create_decoding_key(&self.secret, self.algorithm)?;
+            decode::<Claims>(token, &decoding_key, base_validation)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+        }
+    }
+
     fn clone_token(&self) -> CipherToken {
         CipherToken {
             secret: self.secret.clone(),
             algorithm: self.algorithm,
             access_ttl: self.access_ttl,
             refresh_ttl: self.refresh_ttl,
+            keyset: self.keyset.clone(),
+            default_kid: self.default_kid.clone(),
         }
     }
 
@@ -705,20 +959,61 @@ impl CipherToken {
         Ok(token)
     }
 
-    async fn decode_async_inner(&self, token: &str) -> PyResult<Py<PyDict>> {
-        let mut validation = Validation::new(self.algorithm);
-        validation.validate_exp = true;
-        validation.required_spec_claims.clear();
+    async fn decode_async_inner(&self, token: &str, options: Option<&ValidationOptions>, include_timestamps: bool) -> PyResult<Py<PyDict>> {
+        let mut base_validation = Validation::new(self.algorithm);
+        base_validation.validate_exp = true;
+        base_validation.required_spec_claims.clear();
+        apply_validation_options(&mut base_validation, options);
 
-        let decoding_key = create_decoding_key(&self.secret, self.algorithm)?;
-        
         let token_owned = token.to_string();
-        let token_data: TokenData<Claims> = task::spawn_blocking(move || {
-            decode::<Claims>(&token_owned, &decoding_key, &validation)
-        })
-        .await
-        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+
+        let token_data: TokenData<Claims> = if let Some(keyset) = &self.keyset {
+            let kid = parse_header_kid(token)?;
+            let candidates: Vec<KeyEntry> = match kid {
+                Some(ref kid_value) => {
+                    let entry = keyset.get(kid_value).ok_or_else(|| {
+                        pyo3::exceptions::PyValueError::new_err(format!("No key found for kid: {}", kid_value))
+                    })?;
+                    vec![entry.clone()]
+                }
+                None => keyset.values().cloned().collect(),
+            };
+
+            let mut last_err = None;
+            let mut matched = None;
+            for entry in candidates {
+                let mut entry_validation = base_validation.clone();
+                entry_validation.algorithms = vec![entry.algorithm];
+
+                let decoding_key = create_decoding_key(&entry.secret, entry.algorithm)?;
+                let token_clone = token_owned.clone();
+                let result = task::spawn_blocking(move || {
+                    decode::<Claims>(&token_clone, &decoding_key, &entry_validation)
+                })
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+                match result {
+                    Ok(data) => {
+                        matched = Some(data);
+                        break;
+                    }
+                    Err(e) => last_err = Some(e),
+                }
+            }
+
+            matched.ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(
+                    last_err.map(|e| e.to_string()).unwrap_or_else(|| "No matching key found for token".to_string()),
+                )
+            })?
+        } else {
+            let decoding_key = create_decoding_key(&self.secret, self.algorithm)?;
+            task::spawn_blocking(move || decode::<Claims>(&token_owned, &decoding_key, &base_validation))
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
+        };
 
         Python::with_gil(|py| {
             let dict = PyDict::new(py);
@@ -729,6 +1024,11 @@ impl CipherToken {
             dict.set_item("ttl", token_data.claims.ttl)?;
             dict.set_item("token", token_data.claims.token)?;
             dict.set_item("jti", token_data.claims.jti)?;
+            dict.set_item("header", header_to_dict(py, &token_data.header)?)?;
+
+            if include_timestamps {
+                add_timestamp_fields(dict, token_data.claims.exp, &token_data.claims.extra)?;
+            }
 
             for (key, value) in token_data.claims.extra {
                 let py_value = json_to_python(py, &value)?;
@@ -814,6 +1114,75 @@ pub fn validate_jwt_format(token: &str) -> PyResult<bool> {
     Ok(true)
 }
 
+// base64url-decode a single JWT segment into a JSON value, without any
+// signature or claim verification
+fn decode_jwt_segment(segment: &str) -> PyResult<Value> {
+    let bytes = general_purpose::URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid base64 in token: {}", e)))?;
+
+    serde_json::from_slice(&bytes)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid JSON in token: {}", e)))
+}
+
+// read the `kid` from a token's unverified header, for keyset lookups
+fn parse_header_kid(token: &str) -> PyResult<Option<String>> {
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() != 3 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Invalid JWT format: must have exactly 3 parts separated by dots",
+        ));
+    }
+
+    let header_value = decode_jwt_segment(parts[0])?;
+    if let Value::Object(header_map) = header_value {
+        if let Some(Value::String(kid)) = header_map.get("kid") {
+            return Ok(Some(kid.clone()));
+        }
+    }
+    Ok(None)
+}
+
+fn decode_insecure_inner(py: Python, token: &str, include_timestamps: bool) -> PyResult<Py<PyDict>> {
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() != 3 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Invalid JWT format: must have exactly 3 parts separated by dots",
+        ));
+    }
+
+    let header_value = decode_jwt_segment(parts[0])?;
+    let payload_value = decode_jwt_segment(parts[1])?;
+
+    let dict = PyDict::new(py);
+    dict.set_item("header", json_to_python(py, &header_value)?)?;
+
+    if let Value::Object(claims) = payload_value {
+        if include_timestamps {
+            if let Some(exp) = claims.get("exp").and_then(Value::as_i64) {
+                add_timestamp_fields(dict, exp as usize, &claims)?;
+            }
+        }
+
+        for (key, value) in claims {
+            dict.set_item(key, json_to_python(py, &value)?)?;
+        }
+    } else {
+        dict.set_item("payload", json_to_python(py, &payload_value)?)?;
+    }
+
+    Ok(dict.into())
+}
+
+#[pyfunction]
+#[pyo3(signature = (token, include_timestamps=false))]
+/// unverified decode: split and base64-decode the header and payload of a
+/// token without checking the signature or expiry. DANGEROUS: never trust
+/// the result of this for authorization decisions
+pub fn decode_insecure(py: Python, token: &str, include_timestamps: bool) -> PyResult<Py<PyDict>> {
+    decode_insecure_inner(py, token, include_timestamps)
+}
+
 
 fn python_to_json(py: Python, obj: &PyAny) -> PyResult<Value> {
     if let Ok(s) = obj.extract::<String>() {
@@ -896,8 +1265,10 @@ fn ciphertoken(py: Python, m: &PyModule) -> PyResult<()> {
     pyo3_asyncio::tokio::init(builder);
 
     m.add_class::<CipherToken>()?;
+    m.add_class::<ValidationOptions>()?;
     m.add_function(wrap_pyfunction!(is_jwt_format, m)?)?;
     m.add_function(wrap_pyfunction!(validate_jwt_format, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_insecure, m)?)?;
 
     // ---------------- SECRET MODULE ----------------
     let secret_mod = secret::register_secret_module(py)?;
@@ -916,5 +1287,13 @@ fn ciphertoken(py: Python, m: &PyModule) -> PyResult<()> {
     let algo_mod = algorithms::register_algorithms_module(py)?;
     m.add_submodule(algo_mod.as_ref(py))?;
 
+    // ---------------- KEYEXCHANGE MODULE ----------------
+    let keyexchange_mod = keyexchange::register_keyexchange_module(py)?;
+    m.add_submodule(keyexchange_mod.as_ref(py))?;
+
+    // ---------------- ENCRYPT MODULE ----------------
+    let encrypt_mod = encrypt::register_encrypt_module(py)?;
+    m.add_submodule(encrypt_mod.as_ref(py))?;
+
     Ok(())
 }
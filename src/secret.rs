@@ -4,7 +4,14 @@ use rand::RngCore;
 use base64::{engine::general_purpose, Engine as _};
 use tokio::task;
 use pyo3_asyncio::tokio::future_into_py;
-use rsa::{RsaPrivateKey, RsaPublicKey, pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding}};
+use rsa::{
+    RsaPrivateKey, RsaPublicKey,
+    pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey, EncodeRsaPublicKey},
+    pkcs8::{DecodePrivateKey, EncodePrivateKey, EncodePublicKey, LineEnding},
+};
+use ed25519_dalek::SigningKey;
+use serde_json::{Map, Value};
+use crate::algorithms;
 
 
 
@@ -56,24 +63,172 @@ pub fn generate_hmac_secret_async<'a>(py: Python<'a>, size: usize) -> PyResult<&
 
 
 
+// allowed RSA key sizes and public exponents: anything else is either
+// non-standard or flagged as weak by static analyzers
+const ALLOWED_RSA_BITS: [usize; 3] = [2048, 3072, 4096];
+const ALLOWED_RSA_EXPONENTS: [u64; 2] = [3, 65537];
+
 #[pyfunction]
-pub fn generate_rsa_keypair(bits: Option<usize>) -> PyResult<(String, String)> {
+#[pyo3(signature = (bits=None, public_exponent=None, format=None))]
+pub fn generate_rsa_keypair(
+    bits: Option<usize>,
+    public_exponent: Option<u64>,
+    format: Option<&str>,
+) -> PyResult<(String, String)> {
     let bits = bits.unwrap_or(2048);
 
-    if bits < 2048 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "RSA key size must be at least 2048 bits",
-        ));
+    if !ALLOWED_RSA_BITS.contains(&bits) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "RSA key size must be one of {:?}, got {}",
+            ALLOWED_RSA_BITS, bits
+        )));
+    }
+
+    let exponent = public_exponent.unwrap_or(65537);
+    if !ALLOWED_RSA_EXPONENTS.contains(&exponent) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "RSA public exponent must be one of {:?}, got {}",
+            ALLOWED_RSA_EXPONENTS, exponent
+        )));
     }
 
     let mut rng = OsRng;
 
-    let private_key = RsaPrivateKey::new(&mut rng, bits)
+    let private_key = RsaPrivateKey::new_with_exp(&mut rng, bits, &rsa::BigUint::from(exponent))
         .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
 
     let public_key = RsaPublicKey::from(&private_key);
 
-    let private_pem = private_key
+    match format.unwrap_or("pkcs8").to_lowercase().as_str() {
+        "pkcs8" => {
+            let private_pem = private_key
+                .to_pkcs8_pem(LineEnding::LF)
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+                .to_string();
+
+            let public_pem = public_key
+                .to_public_key_pem(LineEnding::LF)
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok((private_pem, public_pem))
+        }
+        "pkcs1" => {
+            let private_pem = private_key
+                .to_pkcs1_pem(LineEnding::LF)
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+                .to_string();
+
+            let public_pem = public_key
+                .to_pkcs1_pem(LineEnding::LF)
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok((private_pem, public_pem))
+        }
+        "jwk" => rsa_keypair_to_jwk(&private_key, &public_key),
+        other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unsupported key format: {} (expected pkcs8, pkcs1, or jwk)",
+            other
+        ))),
+    }
+}
+
+// base64url-encode a big-endian RSA component, for RFC 7517 JWK output
+fn biguint_to_jwk_b64(value: &rsa::BigUint) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(value.to_bytes_be())
+}
+
+// serialize an RSA keypair as RFC 7517 JSON Web Keys: n/e for the public
+// key, plus d/p/q for the private key
+fn rsa_keypair_to_jwk(private_key: &RsaPrivateKey, public_key: &RsaPublicKey) -> PyResult<(String, String)> {
+    let mut public_jwk = Map::new();
+    public_jwk.insert("kty".to_string(), Value::String("RSA".to_string()));
+    public_jwk.insert("alg".to_string(), Value::String(algorithms::RS256.to_string()));
+    public_jwk.insert("n".to_string(), Value::String(biguint_to_jwk_b64(public_key.n())));
+    public_jwk.insert("e".to_string(), Value::String(biguint_to_jwk_b64(public_key.e())));
+
+    let mut private_jwk = public_jwk.clone();
+    private_jwk.insert("d".to_string(), Value::String(biguint_to_jwk_b64(private_key.d())));
+    let primes = private_key.primes();
+    if let Some(p) = primes.first() {
+        private_jwk.insert("p".to_string(), Value::String(biguint_to_jwk_b64(p)));
+    }
+    if let Some(q) = primes.get(1) {
+        private_jwk.insert("q".to_string(), Value::String(biguint_to_jwk_b64(q)));
+    }
+
+    let private_json = serde_json::to_string(&Value::Object(private_jwk))
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    let public_json = serde_json::to_string(&Value::Object(public_jwk))
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok((private_json, public_json))
+}
+
+#[pyfunction]
+#[pyo3(signature = (bits=None, public_exponent=None))]
+pub fn generate_rsa_keypair_async<'a>(
+    py: Python<'a>,
+    bits: Option<usize>,
+    public_exponent: Option<u64>,
+) -> PyResult<&'a PyAny> {
+    future_into_py(py, async move {
+        let bits = bits.unwrap_or(2048);
+        if !ALLOWED_RSA_BITS.contains(&bits) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "RSA key size must be one of {:?}, got {}",
+                ALLOWED_RSA_BITS, bits
+            )));
+        }
+
+        let exponent = public_exponent.unwrap_or(65537);
+        if !ALLOWED_RSA_EXPONENTS.contains(&exponent) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "RSA public exponent must be one of {:?}, got {}",
+                ALLOWED_RSA_EXPONENTS, exponent
+            )));
+        }
+
+        task::spawn_blocking(move || {
+            let mut rng = OsRng;
+            let private_key = RsaPrivateKey::new_with_exp(&mut rng, bits, &rsa::BigUint::from(exponent))
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+            let public_key = RsaPublicKey::from(&private_key);
+
+            let private_pem = private_key
+                .to_pkcs8_pem(LineEnding::LF)
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+                .to_string();
+
+            let public_pem = public_key
+                .to_public_key_pem(LineEnding::LF)
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok::<(String, String), PyErr>((private_pem, public_pem))
+        })
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+    })
+}
+
+
+#[pyfunction]
+/// generate an EC keypair for ES256 (P-256) or ES384 (P-384)
+pub fn generate_ec_keypair(curve: &str) -> PyResult<(String, String)> {
+    match curve.to_uppercase().as_str() {
+        "P256" | "P-256" | "ES256" => generate_p256_keypair(),
+        "P384" | "P-384" | "ES384" => generate_p384_keypair(),
+        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unsupported curve: {} (expected P-256 or P-384)",
+            curve
+        ))),
+    }
+}
+
+fn generate_p256_keypair() -> PyResult<(String, String)> {
+    let secret_key = p256::SecretKey::random(&mut OsRng);
+    let public_key = secret_key.public_key();
+
+    let private_pem = secret_key
         .to_pkcs8_pem(LineEnding::LF)
         .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
         .to_string();
@@ -85,6 +240,120 @@ pub fn generate_rsa_keypair(bits: Option<usize>) -> PyResult<(String, String)> {
     Ok((private_pem, public_pem))
 }
 
+fn generate_p384_keypair() -> PyResult<(String, String)> {
+    let secret_key = p384::SecretKey::random(&mut OsRng);
+    let public_key = secret_key.public_key();
+
+    let private_pem = secret_key
+        .to_pkcs8_pem(LineEnding::LF)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .to_string();
+
+    let public_pem = public_key
+        .to_public_key_pem(LineEnding::LF)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok((private_pem, public_pem))
+}
+
+#[pyfunction]
+/// generate an EC keypair for ES256 (P-256) or ES384 (P-384) - async
+pub fn generate_ec_keypair_async<'a>(py: Python<'a>, curve: String) -> PyResult<&'a PyAny> {
+    future_into_py(py, async move {
+        let curve_upper = curve.to_uppercase();
+
+        task::spawn_blocking(move || match curve_upper.as_str() {
+            "P256" | "P-256" | "ES256" => generate_p256_keypair(),
+            "P384" | "P-384" | "ES384" => generate_p384_keypair(),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unsupported curve: {} (expected P-256 or P-384)",
+                curve_upper
+            ))),
+        })
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+    })
+}
+
+#[pyfunction]
+/// generate an Ed25519 keypair for EdDSA
+pub fn generate_ed25519_keypair() -> PyResult<(String, String)> {
+    let signing_key = SigningKey::generate(&mut OsRng);
+    let verifying_key = signing_key.verifying_key();
+
+    let private_pem = signing_key
+        .to_pkcs8_pem(LineEnding::LF)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .to_string();
+
+    let public_pem = verifying_key
+        .to_public_key_pem(LineEnding::LF)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok((private_pem, public_pem))
+}
+
+fn parse_rsa_private_key(pem: &str) -> PyResult<RsaPrivateKey> {
+    RsaPrivateKey::from_pkcs8_pem(pem)
+        .or_else(|_| RsaPrivateKey::from_pkcs1_pem(pem))
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Failed to parse RSA private key: {}", e)))
+}
+
+#[pyfunction]
+/// load a PKCS#8 or PKCS#1 RSA private key and re-derive its public PEM
+pub fn load_rsa_private_key(pem: &str) -> PyResult<String> {
+    let private_key = parse_rsa_private_key(pem)?;
+    let public_key = RsaPublicKey::from(&private_key);
+
+    public_key
+        .to_public_key_pem(LineEnding::LF)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+#[pyfunction]
+/// load a PKCS#8 EC private key (P-256 or P-384) and re-derive its public PEM
+pub fn load_ec_private_key(pem: &str, curve: &str) -> PyResult<String> {
+    match curve.to_uppercase().as_str() {
+        "P256" | "P-256" | "ES256" => {
+            let secret_key = p256::SecretKey::from_pkcs8_pem(pem).map_err(|e| {
+                pyo3::exceptions::PyValueError::new_err(format!("Failed to parse EC private key: {}", e))
+            })?;
+            secret_key
+                .public_key()
+                .to_public_key_pem(LineEnding::LF)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+        }
+        "P384" | "P-384" | "ES384" => {
+            let secret_key = p384::SecretKey::from_pkcs8_pem(pem).map_err(|e| {
+                pyo3::exceptions::PyValueError::new_err(format!("Failed to parse EC private key: {}", e))
+            })?;
+            secret_key
+                .public_key()
+                .to_public_key_pem(LineEnding::LF)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+        }
+        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unsupported curve: {} (expected P-256 or P-384)",
+            curve
+        ))),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (pem, key_type="rsa"))]
+/// re-derive the public PEM for a stored private key, dispatching on
+/// `key_type` ("rsa", "p256"/"es256", "p384"/"es384")
+pub fn public_key_from_private(pem: &str, key_type: &str) -> PyResult<String> {
+    match key_type.to_uppercase().as_str() {
+        "RSA" => load_rsa_private_key(pem),
+        "P256" | "P-256" | "ES256" => load_ec_private_key(pem, "P256"),
+        "P384" | "P-384" | "ES384" => load_ec_private_key(pem, "P384"),
+        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unsupported key_type: {} (expected rsa, p256, or p384)",
+            key_type
+        ))),
+    }
+}
 
 pub fn register_secret_module(py: Python) -> PyResult<Py<PyModule>> {
     let m = PyModule::new(py, "secret")?;
@@ -93,5 +362,12 @@ pub fn register_secret_module(py: Python) -> PyResult<Py<PyModule>> {
     m.add_function(wrap_pyfunction!(generate_hmac_secret, m)?)?;
     m.add_function(wrap_pyfunction!(generate_hmac_secret_async, m)?)?;
     m.add_function(wrap_pyfunction!(generate_rsa_keypair, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_rsa_keypair_async, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_ec_keypair, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_ec_keypair_async, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_ed25519_keypair, m)?)?;
+    m.add_function(wrap_pyfunction!(load_rsa_private_key, m)?)?;
+    m.add_function(wrap_pyfunction!(load_ec_private_key, m)?)?;
+    m.add_function(wrap_pyfunction!(public_key_from_private, m)?)?;
     Ok(m.into())
 }
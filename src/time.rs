@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 use std::time::{SystemTime, UNIX_EPOCH};
+use chrono::{TimeZone, Utc};
 
 #[pyfunction]
 pub fn now() -> u64 {
@@ -9,6 +10,17 @@ pub fn now() -> u64 {
         .as_secs()
 }
 
+/// format epoch seconds as an ISO-8601/RFC 3339 UTC datetime string, the
+/// canonical formatter used whenever decode output needs human-readable
+/// `*_datetime` fields alongside the raw epoch seconds
+#[pyfunction]
+pub fn to_iso8601(epoch_secs: i64) -> PyResult<String> {
+    Utc.timestamp_opt(epoch_secs, 0)
+        .single()
+        .map(|dt| dt.to_rfc3339())
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Timestamp out of range"))
+}
+
 #[pyfunction]
 pub fn seconds(n: u64) -> u64 { n }
 
@@ -37,6 +49,7 @@ pub fn weeks(n: u64) -> u64 {
 pub fn register_time_module(py: Python) -> PyResult<Py<PyModule>> {
     let time = PyModule::new(py, "time")?;
     time.add_function(wrap_pyfunction!(now, time)?)?;
+    time.add_function(wrap_pyfunction!(to_iso8601, time)?)?;
     time.add_function(wrap_pyfunction!(seconds, time)?)?;
     time.add_function(wrap_pyfunction!(minutes, time)?)?;
     time.add_function(wrap_pyfunction!(hours, time)?)?;